@@ -1,64 +1,203 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::is_in_test_function;
 use clippy_utils::macros::{find_assert_args, root_macro_call_first_node, PanicExpn};
 use clippy_utils::source::snippet_opt;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::sym;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span, Symbol};
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for `assert!(r.is_ok())` calls.
+    /// Checks for `assert!(r.is_ok())` or `assert!(r.is_err())` calls on `Result`s, and
+    /// `assert!(o.is_some())` or `assert!(o.is_none())` calls on `Option`s.
     ///
     /// ### Why is this bad?
-    /// An assertion failure cannot output a useful message of the error.
-    ///
-    /// ### Known problems
-    /// The error type needs to implement `Debug`.
+    /// An assertion failure cannot output a useful message of the error or value that was held.
     ///
     /// ### Example
     /// ```rust,ignore
     /// # let r = Ok::<_, ()>(());
     /// assert!(r.is_ok());
     /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// # let r = Ok::<_, ()>(());
+    /// r.unwrap();
+    /// ```
+    ///
+    /// ### Configuration
+    /// - `allow-assert-ok-in-tests`: Whether to also accept assertions on result states in test
+    ///   code, where the extra boilerplate often isn't worth it.
     #[clippy::version = "1.64.0"]
     pub ASSERT_OK,
     style,
     "`assert!(r.is_ok())` gives worse error message than directly calling `r.unwrap()`"
 }
 
-declare_lint_pass!(AssertOk => [ASSERT_OK]);
+pub struct Asserts {
+    allow_assert_ok_in_tests: bool,
+}
 
-impl<'tcx> LateLintPass<'tcx> for AssertOk {
+impl Asserts {
+    pub fn new(allow_assert_ok_in_tests: bool) -> Self {
+        Self { allow_assert_ok_in_tests }
+    }
+}
+
+impl_lint_pass!(Asserts => [ASSERT_OK]);
+
+impl<'tcx> LateLintPass<'tcx> for Asserts {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         if_chain! {
             if let Some(macro_call) = root_macro_call_first_node(cx, e);
             if matches!(cx.tcx.get_diagnostic_name(macro_call.def_id), Some(sym::assert_macro));
+            if !self.allow_assert_ok_in_tests || !is_in_test_function(cx.tcx, e.hir_id);
             if let Some((condition, panic_expn)) = find_assert_args(cx, e, macro_call.expn);
-            if matches!(panic_expn, PanicExpn::Empty);
             if let ExprKind::MethodCall(method_segment, args, _) = condition.kind;
-            if method_segment.ident.name == sym!(is_ok);
             let method_receiver = &args[0];
-            if let Some(method_receiver_snippet) = snippet_opt(cx, method_receiver.span);
+            if let Some(receiver_snippet) = snippet_opt(cx, method_receiver.span);
             then {
-                span_lint_and_sugg(
-                    cx,
-                    ASSERT_OK,
-                    macro_call.span,
-                    &format!(
-                        "`assert!({}.is_ok())` gives bad error message",
-                        method_receiver_snippet
-                    ),
-                    "replace with",
-                    format!(
-                        "{}.unwrap()",
-                        method_receiver_snippet
-                    ),
-                    Applicability::Unspecified,
-                );
+                let receiver_ty = cx.typeck_results().expr_ty(method_receiver);
+                let method_name = method_segment.ident.name;
+                if is_type_diagnostic_item(cx, receiver_ty, sym::Result) {
+                    check_result(cx, macro_call.span, receiver_ty, method_name, &receiver_snippet, &panic_expn);
+                } else if is_type_diagnostic_item(cx, receiver_ty, sym::Option) {
+                    check_option(cx, macro_call.span, method_name, &receiver_snippet, &panic_expn);
+                }
             }
         }
     }
 }
+
+/// `assert!(r.is_ok())` / `assert!(r.is_err())` on a `Result<T, E>`. Suggests `unwrap()` /
+/// `unwrap_err()`, provided the type that would be printed on panic implements `Debug`.
+fn check_result<'tcx>(
+    cx: &LateContext<'tcx>,
+    span: Span,
+    receiver_ty: Ty<'tcx>,
+    method_name: Symbol,
+    receiver_snippet: &str,
+    panic_expn: &PanicExpn<'tcx>,
+) {
+    let ty::Adt(_, substs) = receiver_ty.kind() else {
+        return;
+    };
+    let (unwrap_method, debugged_ty, verb) = if method_name == sym!(is_ok) {
+        ("unwrap", substs.type_at(1), "is_ok")
+    } else if method_name == sym!(is_err) {
+        ("unwrap_err", substs.type_at(0), "is_err")
+    } else {
+        return;
+    };
+
+    let Some(debug_trait_def_id) = cx.tcx.get_diagnostic_item(sym::Debug) else {
+        return;
+    };
+    if !implements_trait(cx, debugged_ty, debug_trait_def_id, &[]) {
+        return;
+    }
+
+    let Some((suggestion, applicability)) =
+        unwrap_or_expect_suggestion(cx, receiver_snippet, unwrap_method, panic_expn)
+    else {
+        return;
+    };
+    span_lint_and_sugg(
+        cx,
+        ASSERT_OK,
+        span,
+        &format!("`assert!({receiver_snippet}.{verb}())` gives bad error message"),
+        "replace with",
+        suggestion,
+        applicability,
+    );
+}
+
+/// `assert!(o.is_some())` / `assert!(o.is_none())` on an `Option<T>`. The former has a direct
+/// `unwrap()` equivalent; the latter doesn't, so we can only point towards `assert_eq!`.
+fn check_option<'tcx>(
+    cx: &LateContext<'tcx>,
+    span: Span,
+    method_name: Symbol,
+    receiver_snippet: &str,
+    panic_expn: &PanicExpn<'tcx>,
+) {
+    if method_name == sym!(is_some) {
+        let Some((suggestion, applicability)) =
+            unwrap_or_expect_suggestion(cx, receiver_snippet, "unwrap", panic_expn)
+        else {
+            return;
+        };
+        span_lint_and_sugg(
+            cx,
+            ASSERT_OK,
+            span,
+            &format!("`assert!({receiver_snippet}.is_some())` gives bad error message"),
+            "replace with",
+            suggestion,
+            applicability,
+        );
+    } else if method_name == sym!(is_none) {
+        // The help below spells out `assert_eq!(o, None)` without any message, so it is only
+        // offered when the original assert had no custom message that it would drop.
+        if !matches!(panic_expn, PanicExpn::Empty) {
+            return;
+        }
+        span_lint_and_help(
+            cx,
+            ASSERT_OK,
+            span,
+            &format!("`assert!({receiver_snippet}.is_none())` gives bad error message"),
+            None,
+            &format!("replace with `assert_eq!({receiver_snippet}, None)` for a better failure message"),
+        );
+    }
+}
+
+/// Builds the replacement snippet for a successful `assert!` rewrite: `r.unwrap()` when there
+/// was no custom panic message, or `r.expect(..)` carrying that message along when there was,
+/// so the user doesn't lose their diagnostic context in the rewrite.
+///
+/// `is_ok`/`is_err`/`is_some` borrow the receiver, while `unwrap*`/`expect*` consume it, so a
+/// rewrite that's type-correct today can still break callers that use the receiver again after
+/// the assert. Without proving the receiver is otherwise unused, none of these are safe to apply
+/// automatically, so every branch is `Unspecified`. The `Display`/`Format` cases additionally
+/// move the message's evaluation from "only on assert failure" to "every time, before `expect`
+/// even runs", which can change behavior for side-effecting or expensive message expressions.
+fn unwrap_or_expect_suggestion(
+    cx: &LateContext<'_>,
+    receiver_snippet: &str,
+    unwrap_method: &str,
+    panic_expn: &PanicExpn<'_>,
+) -> Option<(String, Applicability)> {
+    // `unwrap` panics on `None`/`Err`, so its message-carrying counterpart is `expect`; but
+    // `unwrap_err` panics on `Ok`, so it must be paired with `expect_err`, not `expect`, or the
+    // rewrite would flip which state panics.
+    let expect_method = if unwrap_method == "unwrap_err" { "expect_err" } else { "expect" };
+    match panic_expn {
+        PanicExpn::Empty => Some((format!("{receiver_snippet}.{unwrap_method}()"), Applicability::Unspecified)),
+        PanicExpn::Str(msg) => {
+            let msg_snippet = snippet_opt(cx, msg.span)?;
+            Some((format!("{receiver_snippet}.{expect_method}({msg_snippet})"), Applicability::Unspecified))
+        },
+        PanicExpn::Display(msg) => {
+            let msg_snippet = snippet_opt(cx, msg.span)?;
+            Some((
+                format!("{receiver_snippet}.{expect_method}(&format!(\"{{}}\", {msg_snippet}))"),
+                Applicability::Unspecified,
+            ))
+        },
+        PanicExpn::Format(format_args) => {
+            let args_snippet = snippet_opt(cx, format_args.inputs_span())?;
+            Some((
+                format!("{receiver_snippet}.{expect_method}(&format!({args_snippet}))"),
+                Applicability::Unspecified,
+            ))
+        },
+    }
+}
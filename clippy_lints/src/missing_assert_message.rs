@@ -0,0 +1,70 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::{find_assert_args, find_assert_eq_args, root_macro_call_first_node, PanicExpn};
+use clippy_utils::{is_in_cfg_test, is_in_test_function};
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `assert!`, `assert_eq!` and `assert_ne!` invocations that don't carry a
+    /// custom panic message.
+    ///
+    /// ### Why is this bad?
+    /// A bare assertion failure can only explain *what* failed, not *why* it mattered. A custom
+    /// message documents the invariant being protected, which saves the next person reading the
+    /// panic from having to reconstruct that context from the surrounding code.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// assert!(x == 5);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// assert!(x == 5, "x must stay at 5 once initialized");
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub MISSING_ASSERT_MESSAGE,
+    restriction,
+    "checks assertions without a custom panic message"
+}
+declare_lint_pass!(MissingAssertMessage => [MISSING_ASSERT_MESSAGE]);
+
+impl<'tcx> LateLintPass<'tcx> for MissingAssertMessage {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+        let Some(macro_call) = root_macro_call_first_node(cx, e) else {
+            return;
+        };
+        let Some(diag_name) = cx.tcx.get_diagnostic_name(macro_call.def_id) else {
+            return;
+        };
+
+        let panic_expn = if diag_name == sym::assert_macro {
+            find_assert_args(cx, e, macro_call.expn).map(|(_, panic_expn)| panic_expn)
+        } else if diag_name == sym::assert_eq_macro || diag_name == sym::assert_ne_macro {
+            find_assert_eq_args(cx, e, macro_call.expn).map(|(_, _, panic_expn)| panic_expn)
+        } else {
+            return;
+        };
+
+        // Assertion failures in tests are usually easy to localize from the surrounding test
+        // name and body, so a custom message there is mostly noise. Checked only once we know
+        // `e` is actually an assert macro call, since both checks walk the HIR up to the
+        // enclosing item.
+        if is_in_test_function(cx.tcx, e.hir_id) || is_in_cfg_test(cx.tcx, e.hir_id) {
+            return;
+        }
+
+        if let Some(PanicExpn::Empty) = panic_expn {
+            span_lint_and_help(
+                cx,
+                MISSING_ASSERT_MESSAGE,
+                macro_call.span,
+                "assert without any message",
+                None,
+                "consider describing why the failing assert is problematic when it occurs",
+            );
+        }
+    }
+}